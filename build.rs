@@ -0,0 +1,9 @@
+fn main() {
+    // Declare every feature this crate actually reads via `#[cfg(feature = "...")]`
+    // so a misspelled feature name (e.g. "lukcy-number") becomes an
+    // `unexpected_cfgs` compiler warning instead of silently compiling to
+    // nothing.
+    println!(
+        "cargo::rustc-check-cfg=cfg(feature, values(\"print-42\", \"lucky-number\", \"stats-test\"))"
+    );
+}
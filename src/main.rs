@@ -1,8 +1,389 @@
 #[cfg(feature = "lucky-number")]
-fn generate_lucky_number() -> u32 {
-    use rand::Rng;
+mod mt19937 {
+    //! A small, self-contained MT19937 ("Mersenne Twister") implementation.
+    //!
+    //! This is bundled rather than pulled in from `rand_mt` so the crate's
+    //! only RNG dependency stays `rand` itself; it plugs into `rand::Rng`
+    //! via `RngCore` like any other generator.
+
+    const N: usize = 624;
+    const M: usize = 397;
+    const MATRIX_A: u32 = 0x9908_b0df;
+    const UPPER_MASK: u32 = 0x8000_0000;
+    const LOWER_MASK: u32 = 0x7fff_ffff;
+
+    /// "Nothing up my sleeve" default seed: the first three 32-bit words of
+    /// the hex digits of (π − 3), i.e. 0x243f6a8885a308d313198a2e...
+    ///
+    /// Only used by tests (including the `stats-test` suite), to keep their
+    /// output reproducible; runtime code always seeds from `FF_DEMO_SEED` or
+    /// falls back to `thread_rng`.
+    #[cfg(test)]
+    pub(crate) const DEFAULT_SEED: [u32; 3] = [0x243f_6a88, 0x85a3_08d3, 0x1319_8a2e];
+
+    pub(crate) struct Mt19937 {
+        state: [u32; N],
+        index: usize,
+    }
+
+    impl Mt19937 {
+        /// Seed from a single 32-bit word using the standard MT19937 init.
+        pub(crate) fn new(seed: u32) -> Self {
+            let mut state = [0u32; N];
+            state[0] = seed;
+            for i in 1..N {
+                state[i] = 1_812_433_253u32
+                    .wrapping_mul(state[i - 1] ^ (state[i - 1] >> 30))
+                    .wrapping_add(i as u32);
+            }
+            Mt19937 { state, index: N }
+        }
+
+        /// Seed from an array of words (`init_by_array`), used for the
+        /// bundled "nothing up my sleeve" default seed.
+        #[cfg(test)]
+        pub(crate) fn new_from_array(key: &[u32]) -> Self {
+            let mut mt = Self::new(19_650_218);
+            let mut i = 1usize;
+            let mut j = 0usize;
+            for _ in 0..N.max(key.len()) {
+                mt.state[i] = (mt.state[i]
+                    ^ (mt.state[i - 1] ^ (mt.state[i - 1] >> 30)).wrapping_mul(1_664_525))
+                    .wrapping_add(key[j])
+                    .wrapping_add(j as u32);
+                i += 1;
+                j += 1;
+                if i >= N {
+                    mt.state[0] = mt.state[N - 1];
+                    i = 1;
+                }
+                if j >= key.len() {
+                    j = 0;
+                }
+            }
+            for _ in 0..N - 1 {
+                mt.state[i] = (mt.state[i]
+                    ^ (mt.state[i - 1] ^ (mt.state[i - 1] >> 30)).wrapping_mul(1_566_083_941))
+                    .wrapping_sub(i as u32);
+                i += 1;
+                if i >= N {
+                    mt.state[0] = mt.state[N - 1];
+                    i = 1;
+                }
+            }
+            mt.state[0] = 0x8000_0000;
+            mt
+        }
+
+        fn regenerate(&mut self) {
+            for i in 0..N {
+                let y = (self.state[i] & UPPER_MASK) | (self.state[(i + 1) % N] & LOWER_MASK);
+                let mut next = self.state[(i + M) % N] ^ (y >> 1);
+                if y & 1 != 0 {
+                    next ^= MATRIX_A;
+                }
+                self.state[i] = next;
+            }
+            self.index = 0;
+        }
+
+        fn next_word(&mut self) -> u32 {
+            if self.index >= N {
+                self.regenerate();
+            }
+            let mut y = self.state[self.index];
+            self.index += 1;
+
+            // Tempering transform.
+            y ^= y >> 11;
+            y ^= (y << 7) & 0x9d2c_5680;
+            y ^= (y << 15) & 0xefc6_0000;
+            y ^= y >> 18;
+            y
+        }
+    }
+
+    impl rand::RngCore for Mt19937 {
+        fn next_u32(&mut self) -> u32 {
+            self.next_word()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let hi = self.next_word() as u64;
+            let lo = self.next_word() as u64;
+            (hi << 32) | lo
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            let mut chunks = dest.chunks_exact_mut(4);
+            for chunk in &mut chunks {
+                chunk.copy_from_slice(&self.next_word().to_le_bytes());
+            }
+            let rem = chunks.into_remainder();
+            if !rem.is_empty() {
+                let word = self.next_word().to_le_bytes();
+                rem.copy_from_slice(&word[..rem.len()]);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "lucky-number")]
+fn generate_lucky_number_from_rng<R: rand::Rng + ?Sized>(rng: &mut R, lo: u32, hi: u32) -> u32 {
+    rng.gen_range(lo..=hi)
+}
+
+/// Draws a lucky number from `thread_rng` within `[lo, hi]`. Panics if
+/// `lo > hi`; callers taking bounds from the user should validate with
+/// [`validate_range`] first.
+#[cfg(feature = "lucky-number")]
+fn generate_lucky_number_in(lo: u32, hi: u32) -> u32 {
     let mut rng = rand::thread_rng();
-    rng.gen_range(1..=100)
+    generate_lucky_number_from_rng(&mut rng, lo, hi)
+}
+
+#[cfg(feature = "lucky-number")]
+fn generate_lucky_number() -> u32 {
+    generate_lucky_number_in(1, 100)
+}
+
+/// Like [`generate_lucky_number`], but backed by a bundled MT19937
+/// generator freshly seeded from `seed`, so the same seed always yields the
+/// same draw (see `FF_DEMO_SEED`). The underlying [`mt19937::Mt19937`]
+/// stream is what's actually deterministic across draws; construct one
+/// directly (as `main` and the tests do) to pull more than one value from
+/// the same seeded sequence.
+#[cfg(feature = "lucky-number")]
+fn generate_lucky_number_seeded(seed: u32) -> u32 {
+    let mut rng = mt19937::Mt19937::new(seed);
+    generate_lucky_number_from_rng(&mut rng, 1, 100)
+}
+
+/// Checks that a user-supplied range is non-empty, for the `--min`/`--max`
+/// and `FF_DEMO_RANGE` inputs.
+#[cfg(feature = "lucky-number")]
+fn validate_range(lo: u32, hi: u32) -> Result<(u32, u32), String> {
+    if lo <= hi {
+        Ok((lo, hi))
+    } else {
+        Err(format!("invalid range: min ({lo}) must be <= max ({hi})"))
+    }
+}
+
+/// Error returned by [`reject_rand`] when none of
+/// `REJECT_RAND_MAX_ATTEMPTS` draws from the sampled range satisfied the
+/// predicate, so callers never spin forever on an impossible filter. A very
+/// rare accepted value can also run out the attempts.
+#[cfg(feature = "lucky-number")]
+#[derive(Debug)]
+struct RejectionError {
+    lo: u32,
+    hi: u32,
+}
+
+#[cfg(feature = "lucky-number")]
+impl std::fmt::Display for RejectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "no value drawn from {}..={} satisfied the given predicate in {} attempts",
+            self.lo, self.hi, REJECT_RAND_MAX_ATTEMPTS
+        )
+    }
+}
+
+#[cfg(feature = "lucky-number")]
+impl std::error::Error for RejectionError {}
+
+#[cfg(feature = "lucky-number")]
+const REJECT_RAND_MAX_ATTEMPTS: usize = 10_000;
+
+/// Rejection-sampling helper, mirroring nalgebra's `reject_rand` pattern:
+/// draw values from `rng` within `[lo, hi]` until one satisfies `pred`.
+/// Bails out with [`RejectionError`] after `REJECT_RAND_MAX_ATTEMPTS`
+/// rejected draws, so a `pred` that rejects every value in the range does
+/// not loop forever.
+#[cfg(feature = "lucky-number")]
+fn reject_rand<R: rand::Rng + ?Sized, F: FnMut(&u32) -> bool>(
+    rng: &mut R,
+    lo: u32,
+    hi: u32,
+    mut pred: F,
+) -> Result<u32, RejectionError> {
+    std::iter::repeat_with(|| rng.gen_range(lo..=hi))
+        .take(REJECT_RAND_MAX_ATTEMPTS)
+        .find(|n| pred(n))
+        .ok_or(RejectionError { lo, hi })
+}
+
+/// Predicate a lucky number must satisfy, selected via `--filter`.
+#[cfg(feature = "lucky-number")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LuckyFilter {
+    Prime,
+    Even,
+    No13,
+}
+
+#[cfg(feature = "lucky-number")]
+impl LuckyFilter {
+    fn matches(self, n: &u32) -> bool {
+        match self {
+            LuckyFilter::Prime => is_prime(*n),
+            LuckyFilter::Even => n.is_multiple_of(2),
+            LuckyFilter::No13 => *n != 13,
+        }
+    }
+}
+
+#[cfg(feature = "lucky-number")]
+impl std::str::FromStr for LuckyFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prime" => Ok(LuckyFilter::Prime),
+            "even" => Ok(LuckyFilter::Even),
+            "no-13" => Ok(LuckyFilter::No13),
+            other => Err(format!(
+                "unknown --filter value: {other} (expected prime, even, or no-13)"
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "lucky-number")]
+fn is_prime(n: u32) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n.is_multiple_of(2) {
+        return n == 2;
+    }
+    let mut divisor = 3;
+    while divisor <= n / divisor {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 2;
+    }
+    true
+}
+
+/// Looks for `--filter <value>` / `--filter=<value>` among the process
+/// arguments. Returns `None` if the flag wasn't passed, `Some(Err(_))` if it
+/// was passed with an unrecognized value.
+#[cfg(feature = "lucky-number")]
+fn parse_filter_arg() -> Option<Result<LuckyFilter, String>> {
+    let args: Vec<String> = std::env::args().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if let Some(value) = arg.strip_prefix("--filter=") {
+            return Some(value.parse());
+        }
+        if arg == "--filter" {
+            return Some(match args.get(i + 1) {
+                Some(value) => value.parse(),
+                None => Err("--filter requires a value (prime, even, or no-13)".to_string()),
+            });
+        }
+    }
+    None
+}
+
+/// Reads `FF_DEMO_SEED`, returning `Some(seed)` if it's set to a valid
+/// `u32` and `None` if it's unset (callers should fall back to
+/// `thread_rng`). Warns and falls back on an unparseable value too.
+#[cfg(feature = "lucky-number")]
+fn env_seed() -> Option<u32> {
+    match std::env::var("FF_DEMO_SEED") {
+        Ok(val) => match val.parse::<u32>() {
+            Ok(seed) => Some(seed),
+            Err(_) => {
+                eprintln!("FF_DEMO_SEED must be a u32, ignoring: {}", val);
+                None
+            }
+        },
+        Err(_) => None,
+    }
+}
+
+/// Looks for `--min <value>`/`--min=<value>` and `--max <value>`/
+/// `--max=<value>` among the process arguments. Returns `None` if neither
+/// was passed, `Some(Err(_))` on a bad value or an inverted/empty range.
+#[cfg(feature = "lucky-number")]
+fn parse_range_cli_arg() -> Option<Result<(u32, u32), String>> {
+    let args: Vec<String> = std::env::args().collect();
+    let mut min: Option<String> = None;
+    let mut max: Option<String> = None;
+    let mut i = 0;
+    while i < args.len() {
+        if let Some(value) = args[i].strip_prefix("--min=") {
+            min = Some(value.to_string());
+        } else if args[i] == "--min" {
+            // A missing value becomes "", which fails to parse below.
+            min = Some(args.get(i + 1).cloned().unwrap_or_default());
+            i += 1;
+        } else if let Some(value) = args[i].strip_prefix("--max=") {
+            max = Some(value.to_string());
+        } else if args[i] == "--max" {
+            max = Some(args.get(i + 1).cloned().unwrap_or_default());
+            i += 1;
+        }
+        i += 1;
+    }
+    if min.is_none() && max.is_none() {
+        return None;
+    }
+    let parsed = (|| {
+        let lo: u32 = min
+            .unwrap_or_else(|| "1".to_string())
+            .parse()
+            .map_err(|_| "--min must be a u32".to_string())?;
+        let hi: u32 = max
+            .unwrap_or_else(|| "100".to_string())
+            .parse()
+            .map_err(|_| "--max must be a u32".to_string())?;
+        validate_range(lo, hi)
+    })();
+    Some(parsed)
+}
+
+/// Reads `FF_DEMO_RANGE=lo..hi`. Returns `None` if it's unset, `Some(Err(_))`
+/// on a malformed value or an inverted/empty range.
+#[cfg(feature = "lucky-number")]
+fn parse_range_env_var() -> Option<Result<(u32, u32), String>> {
+    let val = std::env::var("FF_DEMO_RANGE").ok()?;
+    let parsed = (|| {
+        let (lo_str, hi_str) = val
+            .split_once("..")
+            .ok_or_else(|| format!("FF_DEMO_RANGE must look like lo..hi, got {val}"))?;
+        let lo: u32 = lo_str
+            .parse()
+            .map_err(|_| format!("FF_DEMO_RANGE min must be a u32, got {lo_str}"))?;
+        let hi: u32 = hi_str
+            .parse()
+            .map_err(|_| format!("FF_DEMO_RANGE max must be a u32, got {hi_str}"))?;
+        validate_range(lo, hi)
+    })();
+    Some(parsed)
+}
+
+/// Resolves the lucky-number range: `--min`/`--max` take priority over
+/// `FF_DEMO_RANGE`, which takes priority over the `1..=100` default.
+#[cfg(feature = "lucky-number")]
+fn resolve_range() -> Result<(u32, u32), String> {
+    if let Some(result) = parse_range_cli_arg() {
+        return result;
+    }
+    if let Some(result) = parse_range_env_var() {
+        return result;
+    }
+    Ok((1, 100))
 }
 
 fn main() {
@@ -14,27 +395,263 @@ fn main() {
 
     #[cfg(feature = "lucky-number")]
     {
-        let lucky = generate_lucky_number();
+        let (lo, hi) = match resolve_range() {
+            Ok(range) => range,
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        };
+
+        let lucky = match parse_filter_arg() {
+            Some(Ok(filter)) => {
+                let result = match env_seed() {
+                    Some(seed) => {
+                        reject_rand(&mut mt19937::Mt19937::new(seed), lo, hi, |n| {
+                            filter.matches(n)
+                        })
+                    }
+                    None => reject_rand(&mut rand::thread_rng(), lo, hi, |n| filter.matches(n)),
+                };
+                match result {
+                    Ok(n) => n,
+                    Err(e) => {
+                        eprintln!("{e}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+            Some(Err(e)) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+            None => match (env_seed(), (lo, hi) == (1, 100)) {
+                (Some(seed), true) => generate_lucky_number_seeded(seed),
+                (Some(seed), false) => {
+                    generate_lucky_number_from_rng(&mut mt19937::Mt19937::new(seed), lo, hi)
+                }
+                (None, true) => generate_lucky_number(),
+                (None, false) => generate_lucky_number_in(lo, hi),
+            },
+        };
         println!("Your lucky number: {}", lucky);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    /// Guards `build.rs`'s `rustc-check-cfg` declaration: compiling a
+    /// snippet with a misspelled `#[cfg(feature = "lukcy-number")]` against
+    /// the same check-cfg list should produce an `unexpected_cfgs` warning
+    /// rather than silently doing nothing.
+    #[test]
+    fn unknown_feature_cfg_triggers_unexpected_cfgs_lint() {
+        use std::process::Command;
+
+        let dir = std::env::temp_dir();
+        let src_path = dir.join("ff_demo_check_cfg_typo.rs");
+        std::fs::write(
+            &src_path,
+            r#"
+                #[cfg(feature = "lukcy-number")]
+                fn unused() {}
+
+                fn main() {}
+            "#,
+        )
+        .expect("failed to write scratch source file");
+
+        let output = Command::new("rustc")
+            .args([
+                "--edition=2021",
+                "--crate-type=bin",
+                "--check-cfg",
+                r#"cfg(feature, values("print-42", "lucky-number", "stats-test"))"#,
+                "--emit=metadata",
+                "--out-dir",
+            ])
+            .arg(&dir)
+            .arg(&src_path)
+            .output()
+            .expect("failed to invoke rustc");
+
+        let _ = std::fs::remove_file(&src_path);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("unexpected_cfgs"),
+            "expected an unexpected_cfgs warning for the misspelled feature, got:\n{stderr}"
+        );
+    }
+
     #[cfg(feature = "lucky-number")]
     #[test]
     fn test_lucky_number_in_range() {
-        use super::generate_lucky_number;
-        for _ in 0..100 {
-            let num = generate_lucky_number();
-            assert!(num >= 1 && num <= 100);
+        use super::generate_lucky_number_in;
+        let bounds = [0u32, 1, 2, 5, 50, 99, 1000];
+        for &lo in &bounds {
+            for &hi in &bounds {
+                if lo > hi {
+                    continue;
+                }
+                for _ in 0..20 {
+                    let num = generate_lucky_number_in(lo, hi);
+                    assert!(num >= lo && num <= hi, "{num} not in [{lo}, {hi}]");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_validate_range_rejects_inverted_or_empty() {
+        use super::validate_range;
+        assert!(validate_range(1, 100).is_ok());
+        assert!(validate_range(5, 5).is_ok());
+        assert!(validate_range(10, 5).is_err());
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_lucky_number_seeded_is_deterministic() {
+        use super::generate_lucky_number_from_rng;
+        use super::mt19937::Mt19937;
+
+        // Drawing repeatedly from two generators seeded alike must produce
+        // the same sequence, not just the same first value.
+        let mut rng_a = Mt19937::new(42);
+        let mut rng_b = Mt19937::new(42);
+        let a: Vec<u32> = (0..10)
+            .map(|_| generate_lucky_number_from_rng(&mut rng_a, 1, 100))
+            .collect();
+        let b: Vec<u32> = (0..10)
+            .map(|_| generate_lucky_number_from_rng(&mut rng_b, 1, 100))
+            .collect();
+        assert_eq!(a, b);
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_generate_lucky_number_seeded_matches_single_draw() {
+        use super::mt19937::Mt19937;
+        use super::{generate_lucky_number_from_rng, generate_lucky_number_seeded};
+        assert_eq!(generate_lucky_number_seeded(42), generate_lucky_number_seeded(42));
+        assert_eq!(
+            generate_lucky_number_seeded(42),
+            generate_lucky_number_from_rng(&mut Mt19937::new(42), 1, 100)
+        );
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_lucky_number_default_seed_exact_value() {
+        use super::generate_lucky_number_from_rng;
+        use super::mt19937::{Mt19937, DEFAULT_SEED};
+        let mut rng = Mt19937::new_from_array(&DEFAULT_SEED);
+        assert_eq!(generate_lucky_number_from_rng(&mut rng, 1, 100), 29);
+        assert_eq!(generate_lucky_number_from_rng(&mut rng, 1, 100), 75);
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_reject_rand_respects_predicate() {
+        use super::reject_rand;
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let n = reject_rand(&mut rng, 1, 100, |n| n.is_multiple_of(2)).unwrap();
+            assert_eq!(n % 2, 0);
+        }
+        for _ in 0..50 {
+            let n = reject_rand(&mut rng, 1, 100, |n| *n != 13).unwrap();
+            assert_ne!(n, 13);
         }
     }
 
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_reject_rand_errors_on_impossible_predicate() {
+        use super::reject_rand;
+        let mut rng = rand::thread_rng();
+        assert!(reject_rand(&mut rng, 1, 100, |_| false).is_err());
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_lucky_filter_matches() {
+        use super::LuckyFilter;
+        assert!(LuckyFilter::Even.matches(&4));
+        assert!(!LuckyFilter::Even.matches(&5));
+        assert!(LuckyFilter::Prime.matches(&7));
+        assert!(!LuckyFilter::Prime.matches(&8));
+        assert!(LuckyFilter::No13.matches(&12));
+        assert!(!LuckyFilter::No13.matches(&13));
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_lucky_filter_prime_near_u32_max_does_not_overflow() {
+        use super::LuckyFilter;
+        // 4_294_967_291 is the largest prime below u32::MAX; divisor*divisor
+        // would overflow u32 before the loop terminates if not guarded.
+        assert!(LuckyFilter::Prime.matches(&4_294_967_291));
+        assert!(!LuckyFilter::Prime.matches(&4_294_967_290));
+    }
+
+    #[cfg(feature = "lucky-number")]
+    #[test]
+    fn test_lucky_filter_from_str() {
+        use super::LuckyFilter;
+        assert_eq!("prime".parse(), Ok(LuckyFilter::Prime));
+        assert_eq!("even".parse(), Ok(LuckyFilter::Even));
+        assert_eq!("no-13".parse(), Ok(LuckyFilter::No13));
+        assert!("bogus".parse::<LuckyFilter>().is_err());
+    }
+
     #[cfg(not(feature = "lucky-number"))]
     #[test]
     fn test_no_lucky_number_feature() {
         // This test verifies the code compiles without the feature
-        assert!(true);
+    }
+}
+
+/// Expensive distribution tests, analogous to Rust's own long-running
+/// float-parse test harness: compiled and run only with `--features
+/// stats-test`, since a single run draws ten million samples and
+/// takes seconds-to-minutes. Excluded from the default `cargo test` run.
+#[cfg(all(test, feature = "stats-test"))]
+mod stats_test {
+    use super::generate_lucky_number_from_rng;
+    use super::mt19937::{Mt19937, DEFAULT_SEED};
+
+    /// 0.999 critical value of the chi-square distribution for 99 degrees
+    /// of freedom (100 bins − 1).
+    const CHI_SQUARE_CRITICAL_VALUE_P999_DF99: f64 = 148.230;
+
+    #[test]
+    fn lucky_number_is_uniform_over_many_draws() {
+        const SAMPLES: u64 = 10_000_000;
+        const BINS: usize = 100;
+
+        let mut rng = Mt19937::new_from_array(&DEFAULT_SEED);
+        let mut counts = [0u64; BINS];
+        for _ in 0..SAMPLES {
+            let n = generate_lucky_number_from_rng(&mut rng, 1, 100);
+            counts[(n - 1) as usize] += 1;
+        }
+
+        let expected = SAMPLES as f64 / BINS as f64;
+        let chi_square: f64 = counts
+            .iter()
+            .map(|&observed| {
+                let diff = observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum();
+
+        assert!(
+            chi_square < CHI_SQUARE_CRITICAL_VALUE_P999_DF99,
+            "chi-square statistic {chi_square} exceeds the 0.999 critical value \
+             ({CHI_SQUARE_CRITICAL_VALUE_P999_DF99}) for 99 degrees of freedom \
+             -- generate_lucky_number's output no longer looks uniform"
+        );
     }
 }